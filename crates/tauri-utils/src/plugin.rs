@@ -9,68 +9,517 @@ pub use build::*;
 #[cfg(feature = "build")]
 mod build {
   use std::{
+    collections::HashSet,
     env::{vars_os, var},
     fs,
     path::{Path, PathBuf},
   };
 
+  use serde::Deserialize;
+
   const GLOBAL_API_SCRIPT_PATH_KEY: &str = "GLOBAL_API_SCRIPT_PATH";
+  const GLOBAL_API_SCRIPT_NAME_KEY: &str = "GLOBAL_API_SCRIPT_NAME";
+  const GLOBAL_API_SCRIPT_VERSION_KEY: &str = "GLOBAL_API_SCRIPT_VERSION";
+  const GLOBAL_API_SCRIPT_AFTER_KEY: &str = "GLOBAL_API_SCRIPT_AFTER";
   /// Known file name of the file that contains an array with the path of all API scripts defined with [`define_global_api_script_path`].
   pub const GLOBAL_API_SCRIPT_FILE_LIST_PATH: &str = "__global-api-script.js";
+  /// Known file name of the file that contains the richer per-script metadata collected alongside
+  /// [`GLOBAL_API_SCRIPT_FILE_LIST_PATH`].
+  pub const GLOBAL_API_SCRIPT_METADATA_FILE_LIST_PATH: &str = "__global-api-script-metadata.json";
+  /// Known file name of a plugin manifest that can declaratively register global API scripts.
+  pub const PLUGIN_MANIFEST_FILE_NAME: &str = "tauri-plugin.toml";
+
+  /// Env var that, when set, takes priority over everything else as the root that global API
+  /// script paths are emitted relative to. Lets build systems other than Bazel or plain Cargo
+  /// opt into relative paths without the crate having to know about them.
+  const SCRIPT_ROOT_ENV: &str = "TAURI_SCRIPT_ROOT";
+
+  /// Picks the root that global API script paths should be emitted relative to, in priority order:
+  /// an explicit [`SCRIPT_ROOT_ENV`] override, then `BAZEL_OUTPUT_BASE` if we're building under
+  /// Bazel, then `CARGO_MANIFEST_DIR` as the plain-Cargo default.
+  fn resolve_script_root() -> Option<PathBuf> {
+    var(SCRIPT_ROOT_ENV)
+      .or_else(|_| var("BAZEL_OUTPUT_BASE"))
+      .or_else(|_| var("CARGO_MANIFEST_DIR"))
+      .ok()
+      .map(PathBuf::from)
+  }
+
+  /// Strips the `\\?\` verbatim-path prefix that `Path::canonicalize` adds on Windows, so the
+  /// canonical path can be meaningfully compared against plain (non-verbatim) roots like
+  /// `CARGO_MANIFEST_DIR` or `BAZEL_OUTPUT_BASE` with `strip_prefix`. A no-op on other platforms.
+  fn clean_canonical_path(path: PathBuf) -> PathBuf {
+    const VERBATIM_PREFIX: &str = r"\\?\";
+
+    if cfg!(windows) {
+      let path_str = path.display().to_string();
+      if let Some(stripped) = path_str.strip_prefix(VERBATIM_PREFIX) {
+        return PathBuf::from(stripped);
+      }
+    }
+
+    path
+  }
 
   /// Defines the path to the global API script using Cargo instructions.
+  ///
+  /// The path is canonicalized (resolving symlinks) and then emitted relative to whatever
+  /// [`resolve_script_root`] picks, so the same plugin build script works whether it's invoked by
+  /// Bazel, Cargo directly, or anything else that sets [`SCRIPT_ROOT_ENV`]. If the resolved root
+  /// isn't a prefix of the canonical path, the absolute canonical path is emitted instead of panicking.
   pub fn define_global_api_script_path(path: &Path) {
-    // NOTE(lreyna): We want paths to the paths that are stored in the `.depenv` output to be relative.
+    // NOTE(lreyna): We want the paths that are stored in the `.depenv` output to be relative.
     // Otherwise, you might get a path that doesn't exist on your system (either an old sandbox or a path from remote cache on jenkins)
     // We get the canonical path (resolved symlinks) and get the relative path of the global script.
-    // When the path is read later, it will be resolved with the same bazel output base path
+    // When the path is read later, it will be resolved with the same root path.
     // i.e. Example Output: DEP_TAURI_PLUGIN_CORS_FETCH_GLOBAL_API_SCRIPT_PATH=external/tauri-deps__tauri-plugin-cors-fetch-4.1.0/api-iife.js
-    let bazel_output_base = PathBuf::from(var("BAZEL_OUTPUT_BASE").expect("BAZEL_OUTPUT_BASE not set"));
-
     let resolved_path = if path.is_relative() {
       PathBuf::from(var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set")).join(path)
     } else {
       path.to_path_buf()
     };
-  
-    let canon_path = resolved_path.canonicalize().expect("failed to canonicalize global API script path");
-    let cleaned_canon_path = crate::config::parse::clean_canonical_path(canon_path);
-    let relative_path = cleaned_canon_path.strip_prefix(&bazel_output_base).expect("failed to get relative path of global API script");
+
+    let canon_path = resolved_path
+      .canonicalize()
+      .expect("failed to canonicalize global API script path");
+    let canon_path = clean_canonical_path(canon_path);
+
+    let emitted_path = match resolve_script_root() {
+      Some(root) => match canon_path.strip_prefix(&root) {
+        Ok(relative_path) => relative_path.to_path_buf(),
+        Err(_) => canon_path,
+      },
+      None => canon_path,
+    };
 
     println!(
       "cargo:{GLOBAL_API_SCRIPT_PATH_KEY}={}",
-      relative_path.display()
+      emitted_path.display()
+    );
+
+    // Piggyback the originating crate's name and version alongside the path so downstream
+    // consumers (see `save_global_api_scripts_paths`) can attribute the script back to its plugin.
+    if let Ok(name) = var("CARGO_PKG_NAME") {
+      println!("cargo:{GLOBAL_API_SCRIPT_NAME_KEY}={name}");
+    }
+    if let Ok(version) = var("CARGO_PKG_VERSION") {
+      println!("cargo:{GLOBAL_API_SCRIPT_VERSION_KEY}={version}");
+    }
+  }
+
+  /// Declares that this crate's global API script must be injected after the global API scripts
+  /// of `crate_names`, regardless of the order [`save_global_api_scripts_paths`] otherwise
+  /// observes the `DEP_*` env vars in. Call this alongside [`define_global_api_script_path`] from
+  /// a plugin's build script when its injected globals depend on another plugin's being present first.
+  pub fn define_global_api_script_after(crate_names: &[&str]) {
+    println!("cargo:{GLOBAL_API_SCRIPT_AFTER_KEY}={}", crate_names.join(","));
+  }
+
+  /// Errors that can occur while parsing a [`PLUGIN_MANIFEST_FILE_NAME`] manifest.
+  #[derive(Debug, thiserror::Error)]
+  pub enum ManifestError {
+    /// Failed to read the manifest file from disk.
+    #[error("failed to read plugin manifest {path}: {source}")]
+    Io {
+      /// Path of the manifest that failed to be read.
+      path: PathBuf,
+      /// Underlying IO error.
+      source: std::io::Error,
+    },
+    /// The manifest contents are not valid TOML or don't match the expected shape.
+    #[error("failed to parse plugin manifest {path}: {source}")]
+    Parse {
+      /// Path of the manifest that failed to parse.
+      path: PathBuf,
+      /// Underlying TOML error.
+      source: toml::de::Error,
+    },
+    /// A script declared in the manifest points to a file that does not exist.
+    #[error("global API script {0} declared in the plugin manifest does not exist")]
+    MissingScript(PathBuf),
+    /// A key in the manifest has a value that isn't one of the accepted values.
+    #[error("invalid value `{value}` for key `{key}` in the plugin manifest")]
+    InvalidKey {
+      /// Name of the offending key.
+      key: String,
+      /// The value that was rejected.
+      value: String,
+    },
+    /// The same script was declared more than once in the manifest.
+    #[error("global API script {0} is registered more than once in the plugin manifest")]
+    DuplicateRegistration(PathBuf),
+  }
+
+  /// When a global API script declared in a manifest should be injected relative to the page load.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, Deserialize)]
+  pub enum GlobalApiScriptInjection {
+    /// Inject the script before the page's own scripts run.
+    #[serde(rename = "before-load")]
+    BeforeLoad,
+    /// Inject the script after the page's own scripts run.
+    #[serde(rename = "after-load")]
+    AfterLoad,
+  }
+
+  /// A single global API script declared in a [`PLUGIN_MANIFEST_FILE_NAME`] manifest, after validation.
+  #[derive(Debug, Clone)]
+  pub struct ManifestGlobalApiScript {
+    /// Path to the script file, resolved against the manifest's parent directory. This is only
+    /// guaranteed absolute if the `manifest_path` given to [`parse_global_api_scripts_manifest`]
+    /// was itself absolute — unlike the env-var path ([`define_global_api_script_path`]), this
+    /// function never canonicalizes the result.
+    pub path: PathBuf,
+    /// When the script should be injected.
+    pub inject: GlobalApiScriptInjection,
+    /// Optional os/arch filter the script only applies to, e.g. `windows` or `aarch64`.
+    pub target: Option<String>,
+    /// Names of the crates whose global API scripts must be loaded before this one.
+    pub after: Vec<String>,
+    /// Optional per-script identifier, so one manifest can declare several scripts and order them
+    /// relative to each other (the owning crate's name alone can't distinguish between them).
+    pub name: Option<String>,
+  }
+
+  #[derive(Debug, Default, Deserialize)]
+  struct RawPluginManifest {
+    #[serde(default, rename = "global-api-scripts")]
+    global_api_scripts: Vec<RawGlobalApiScript>,
+  }
+
+  #[derive(Debug, Deserialize)]
+  struct RawGlobalApiScript {
+    path: PathBuf,
+    inject: Option<String>,
+    target: Option<String>,
+    #[serde(default)]
+    after: Vec<String>,
+    name: Option<String>,
+  }
+
+  /// Parses a [`PLUGIN_MANIFEST_FILE_NAME`] manifest (or `[[global-api-scripts]]` array-of-tables
+  /// entries embedded in another TOML manifest) into a validated list of [`ManifestGlobalApiScript`].
+  ///
+  /// Returns an empty list if `manifest_path` doesn't exist, since shipping a manifest is optional.
+  /// Relative script paths are resolved against the manifest's parent directory.
+  pub fn parse_global_api_scripts_manifest(
+    manifest_path: &Path,
+  ) -> Result<Vec<ManifestGlobalApiScript>, ManifestError> {
+    if !manifest_path.exists() {
+      return Ok(Vec::new());
+    }
+
+    let contents = fs::read_to_string(manifest_path).map_err(|source| ManifestError::Io {
+      path: manifest_path.to_path_buf(),
+      source,
+    })?;
+    let raw: RawPluginManifest = toml::from_str(&contents).map_err(|source| ManifestError::Parse {
+      path: manifest_path.to_path_buf(),
+      source,
+    })?;
+
+    let manifest_dir = manifest_path.parent().unwrap_or_else(|| Path::new("."));
+    let mut seen = HashSet::new();
+    let mut scripts = Vec::new();
+
+    for raw_script in raw.global_api_scripts {
+      let inject = match raw_script.inject.as_deref() {
+        None | Some("before-load") => GlobalApiScriptInjection::BeforeLoad,
+        Some("after-load") => GlobalApiScriptInjection::AfterLoad,
+        Some(other) => {
+          return Err(ManifestError::InvalidKey {
+            key: "inject".into(),
+            value: other.into(),
+          })
+        }
+      };
+
+      let script_path = if raw_script.path.is_relative() {
+        manifest_dir.join(&raw_script.path)
+      } else {
+        raw_script.path.clone()
+      };
+
+      if !script_path.exists() {
+        return Err(ManifestError::MissingScript(script_path));
+      }
+      if !seen.insert(script_path.clone()) {
+        return Err(ManifestError::DuplicateRegistration(script_path));
+      }
+
+      scripts.push(ManifestGlobalApiScript {
+        path: script_path,
+        inject,
+        target: raw_script.target,
+        after: raw_script.after,
+        name: raw_script.name,
+      });
+    }
+
+    Ok(scripts)
+  }
+
+  /// Per-script metadata collected by [`save_global_api_scripts_paths`], saved alongside the flat
+  /// path array so tooling (IDEs, bundlers, audit scripts) can attribute an injected script back to
+  /// the plugin that registered it.
+  #[derive(Debug, Clone, serde::Serialize, Deserialize)]
+  pub struct GlobalApiScriptMetadata {
+    /// Name of the crate that registered the script, if known.
+    pub crate_name: Option<String>,
+    /// Version of the crate that registered the script, if known.
+    pub crate_version: Option<String>,
+    /// The `DEP_*` env key the script path was read from, or a `manifest:` pseudo-key when the
+    /// script was declared through a [`PLUGIN_MANIFEST_FILE_NAME`] instead.
+    pub env_key: String,
+    /// Resolved path of the script, as written to [`GLOBAL_API_SCRIPT_FILE_LIST_PATH`].
+    pub path: PathBuf,
+    /// Names of the crates whose global API scripts must be loaded before this one.
+    pub after: Vec<String>,
+    /// When the script should be injected.
+    pub inject: GlobalApiScriptInjection,
+    /// Optional os/arch filter the script only applies to, e.g. `windows` or `aarch64`. Scripts
+    /// whose target doesn't match the current build (see [`global_api_script_target_matches`])
+    /// are dropped before this metadata is ever constructed.
+    pub target: Option<String>,
+    /// Optional per-script identifier (see [`ManifestGlobalApiScript::name`]), used alongside
+    /// `crate_name` to match `after` declarations so scripts from the same manifest can order
+    /// themselves relative to each other.
+    pub script_name: Option<String>,
+  }
+
+  /// Whether a manifest-declared script's `target` filter matches the build currently running,
+  /// comparing it against `CARGO_CFG_TARGET_OS` and `CARGO_CFG_TARGET_ARCH`. A script with no
+  /// `target` always matches.
+  fn global_api_script_target_matches(target: &Option<String>) -> bool {
+    match target {
+      None => true,
+      Some(target) => {
+        var("CARGO_CFG_TARGET_OS").ok().as_deref() == Some(target.as_str())
+          || var("CARGO_CFG_TARGET_ARCH").ok().as_deref() == Some(target.as_str())
+      }
+    }
+  }
+
+  /// Errors that can occur while ordering global API scripts by their declared load-order dependencies.
+  #[derive(Debug, thiserror::Error)]
+  pub enum ScriptOrderingError {
+    /// Scripts declared `after` dependencies on each other (or a script on itself), so no valid
+    /// order exists.
+    #[error("dependency cycle detected among global API scripts: {0}")]
+    Cycle(String),
+  }
+
+  /// Topologically sorts `scripts` so that any script declaring `after: ["some-name"]` comes after
+  /// every script whose `crate_name` or `script_name` is `some-name`. Ties (including scripts with
+  /// no dependencies relative to each other) are broken by crate name, falling back to script name
+  /// and then `env_key`, so the order is reproducible across builds regardless of env-var
+  /// iteration order.
+  fn order_global_api_scripts(
+    mut scripts: Vec<GlobalApiScriptMetadata>,
+  ) -> Result<Vec<GlobalApiScriptMetadata>, ScriptOrderingError> {
+    let len = scripts.len();
+    let sort_key = |s: &GlobalApiScriptMetadata| {
+      s.crate_name
+        .clone()
+        .or_else(|| s.script_name.clone())
+        .unwrap_or_else(|| s.env_key.clone())
+    };
+
+    // `dependents[j]` lists scripts that must come after script `j`.
+    let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); len];
+    let mut remaining_deps = vec![0usize; len];
+
+    for (i, script) in scripts.iter().enumerate() {
+      for after_name in &script.after {
+        for (j, other) in scripts.iter().enumerate() {
+          if other.crate_name.as_deref() == Some(after_name.as_str())
+            || other.script_name.as_deref() == Some(after_name.as_str())
+          {
+            dependents[j].push(i);
+            remaining_deps[i] += 1;
+          }
+        }
+      }
+    }
+
+    let mut ready: Vec<usize> = (0..len).filter(|&i| remaining_deps[i] == 0).collect();
+    let mut order = Vec::with_capacity(len);
+
+    while !ready.is_empty() {
+      ready.sort_by(|&a, &b| sort_key(&scripts[a]).cmp(&sort_key(&scripts[b])));
+      let node = ready.remove(0);
+      order.push(node);
+      for &dependent in &dependents[node] {
+        remaining_deps[dependent] -= 1;
+        if remaining_deps[dependent] == 0 {
+          ready.push(dependent);
+        }
+      }
+    }
+
+    if order.len() != len {
+      let mut cyclic: Vec<String> = (0..len)
+        .filter(|i| !order.contains(i))
+        .map(|i| sort_key(&scripts[i]))
+        .collect();
+      cyclic.sort();
+      return Err(ScriptOrderingError::Cycle(cyclic.join(", ")));
+    }
+
+    let mut slots: Vec<Option<GlobalApiScriptMetadata>> = scripts.drain(..).map(Some).collect();
+    Ok(
+      order
+        .into_iter()
+        .map(|i| slots[i].take().expect("each index is visited exactly once"))
+        .collect(),
     )
   }
 
+  /// Errors that can occur while collecting and saving global API scripts.
+  #[derive(Debug, thiserror::Error)]
+  pub enum GlobalApiScriptsError {
+    /// Failed to parse a plugin manifest.
+    #[error(transparent)]
+    Manifest(#[from] ManifestError),
+    /// Failed to order scripts by their declared load-order dependencies.
+    #[error(transparent)]
+    Ordering(#[from] ScriptOrderingError),
+  }
+
   /// Collects the path of all the global API scripts defined with [`define_global_api_script_path`]
   /// and saves them to the out dir with filename [`GLOBAL_API_SCRIPT_FILE_LIST_PATH`].
   ///
   /// `tauri_global_scripts` is only used in Tauri's monorepo for the examples to work
-  /// since they don't have a build script to run `tauri-build` and pull in the deps env vars
-  pub fn save_global_api_scripts_paths(out_dir: &Path, mut tauri_global_scripts: Option<PathBuf>) {
-    let mut scripts = Vec::new();
+  /// since they don't have a build script to run `tauri-build` and pull in the deps env vars.
+  ///
+  /// No [`PLUGIN_MANIFEST_FILE_NAME`] is read here; use
+  /// [`save_global_api_scripts_paths_with_manifest`] to merge manifest-declared scripts as well.
+  pub fn save_global_api_scripts_paths(
+    out_dir: &Path,
+    mut tauri_global_scripts: Option<PathBuf>,
+  ) {
+    save_global_api_scripts_paths_with_manifest(out_dir, tauri_global_scripts.take(), None)
+      .expect("failed to save global API script paths")
+  }
 
-    for (key, value) in vars_os() {
-      let key = key.to_string_lossy();
+  /// Same as [`save_global_api_scripts_paths`] but also merges scripts declared in the
+  /// [`PLUGIN_MANIFEST_FILE_NAME`] at `manifest_path`, if any.
+  ///
+  /// Manifest-declared scripts are merged in alongside the ones registered through `DEP_*` env
+  /// vars, skipping any whose `target` doesn't match the current build (see
+  /// [`global_api_script_target_matches`]) and carrying their `inject` timing through to
+  /// [`GlobalApiScriptMetadata`].
+  ///
+  /// The collected scripts (env-var and manifest-declared alike) are topologically sorted by their
+  /// declared `after` load-order dependencies before being written out, with ties broken by crate
+  /// name, so the resulting order is deterministic and dependency-correct regardless of the order
+  /// `vars_os` happens to yield. `tauri_global_scripts`, when present, is still always placed first.
+  pub fn save_global_api_scripts_paths_with_manifest(
+    out_dir: &Path,
+    mut tauri_global_scripts: Option<PathBuf>,
+    manifest_path: Option<&Path>,
+  ) -> Result<(), GlobalApiScriptsError> {
+    let env_vars: Vec<(String, std::ffi::OsString)> = vars_os()
+      .map(|(key, value)| (key.to_string_lossy().into_owned(), value))
+      .collect();
+
+    let mut metadata = Vec::new();
 
-      if key == format!("DEP_TAURI_{GLOBAL_API_SCRIPT_PATH_KEY}") {
+    for (key, value) in &env_vars {
+      if key == &format!("DEP_TAURI_{GLOBAL_API_SCRIPT_PATH_KEY}") {
         tauri_global_scripts = Some(PathBuf::from(value));
       } else if key.starts_with("DEP_") && key.ends_with(GLOBAL_API_SCRIPT_PATH_KEY) {
         let script_path = PathBuf::from(value);
-        scripts.push(script_path);
+        let crate_prefix = &key[..key.len() - GLOBAL_API_SCRIPT_PATH_KEY.len()];
+
+        let find_sibling = |suffix: &str| {
+          env_vars
+            .iter()
+            .find(|(k, _)| k == &format!("{crate_prefix}{suffix}"))
+            .map(|(_, v)| v.to_string_lossy().into_owned())
+        };
+
+        let after = find_sibling(GLOBAL_API_SCRIPT_AFTER_KEY)
+          .map(|csv| {
+            csv
+              .split(',')
+              .map(str::trim)
+              .filter(|s| !s.is_empty())
+              .map(str::to_string)
+              .collect()
+          })
+          .unwrap_or_default();
+
+        metadata.push(GlobalApiScriptMetadata {
+          crate_name: find_sibling(GLOBAL_API_SCRIPT_NAME_KEY),
+          crate_version: find_sibling(GLOBAL_API_SCRIPT_VERSION_KEY),
+          env_key: key.clone(),
+          path: script_path,
+          after,
+          inject: GlobalApiScriptInjection::BeforeLoad,
+          target: None,
+          script_name: None,
+        });
       }
     }
 
+    if let Some(manifest_path) = manifest_path {
+      // `save_global_api_scripts_paths_with_manifest` runs from the owning crate's own build
+      // script, so its `CARGO_PKG_NAME`/`CARGO_PKG_VERSION` identify the manifest's scripts the
+      // same way `define_global_api_script_path` does for the env-var path.
+      let manifest_crate_name = var("CARGO_PKG_NAME").ok();
+      let manifest_crate_version = var("CARGO_PKG_VERSION").ok();
+
+      for manifest_script in parse_global_api_scripts_manifest(manifest_path)? {
+        if !global_api_script_target_matches(&manifest_script.target) {
+          continue;
+        }
+
+        metadata.push(GlobalApiScriptMetadata {
+          crate_name: manifest_crate_name.clone(),
+          crate_version: manifest_crate_version.clone(),
+          env_key: format!("manifest:{}", manifest_path.display()),
+          path: manifest_script.path,
+          after: manifest_script.after,
+          inject: manifest_script.inject,
+          target: manifest_script.target,
+          script_name: manifest_script.name,
+        });
+      }
+    }
+
+    let mut metadata = order_global_api_scripts(metadata)?;
+
     if let Some(tauri_global_scripts) = tauri_global_scripts {
-      scripts.insert(0, tauri_global_scripts);
+      metadata.insert(
+        0,
+        GlobalApiScriptMetadata {
+          crate_name: Some("tauri".into()),
+          crate_version: None,
+          env_key: format!("DEP_TAURI_{GLOBAL_API_SCRIPT_PATH_KEY}"),
+          path: tauri_global_scripts,
+          after: Vec::new(),
+          inject: GlobalApiScriptInjection::BeforeLoad,
+          target: None,
+          script_name: None,
+        },
+      );
     }
 
+    let scripts: Vec<&PathBuf> = metadata.iter().map(|m| &m.path).collect();
+
     fs::write(
       out_dir.join(GLOBAL_API_SCRIPT_FILE_LIST_PATH),
       serde_json::to_string(&scripts).expect("failed to serialize global API script paths"),
     )
     .expect("failed to write global API script");
+
+    fs::write(
+      out_dir.join(GLOBAL_API_SCRIPT_METADATA_FILE_LIST_PATH),
+      serde_json::to_string(&metadata).expect("failed to serialize global API script metadata"),
+    )
+    .expect("failed to write global API script metadata");
+
+    Ok(())
   }
 
   /// Read global api scripts from [`GLOBAL_API_SCRIPT_FILE_LIST_PATH`]
@@ -99,4 +548,333 @@ mod build {
         .collect(),
     )
   }
+
+  /// Read the per-script metadata from [`GLOBAL_API_SCRIPT_METADATA_FILE_LIST_PATH`], saved
+  /// alongside [`GLOBAL_API_SCRIPT_FILE_LIST_PATH`] by [`save_global_api_scripts_paths`].
+  pub fn read_global_api_scripts_metadata(out_dir: &Path) -> Option<Vec<GlobalApiScriptMetadata>> {
+    let metadata_path = out_dir.join(GLOBAL_API_SCRIPT_METADATA_FILE_LIST_PATH);
+    if !metadata_path.exists() {
+      return None;
+    }
+
+    let metadata_str = fs::read_to_string(metadata_path)
+      .expect("failed to read plugin global API script metadata");
+
+    Some(
+      serde_json::from_str(&metadata_str)
+        .expect("failed to parse plugin global API script metadata"),
+    )
+  }
+
+  #[cfg(test)]
+  mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// `resolve_script_root` reads process-wide env vars, so tests that set them must not
+    /// interleave with each other.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    fn script(crate_name: &str, after: &[&str]) -> GlobalApiScriptMetadata {
+      GlobalApiScriptMetadata {
+        crate_name: Some(crate_name.to_string()),
+        crate_version: None,
+        env_key: format!("DEP_{}_{GLOBAL_API_SCRIPT_PATH_KEY}", crate_name.to_uppercase()),
+        path: PathBuf::from(format!("{crate_name}.js")),
+        after: after.iter().map(|s| s.to_string()).collect(),
+        inject: GlobalApiScriptInjection::BeforeLoad,
+        target: None,
+        script_name: None,
+      }
+    }
+
+    fn ordered_names(scripts: Vec<GlobalApiScriptMetadata>) -> Vec<String> {
+      order_global_api_scripts(scripts)
+        .expect("should not cycle")
+        .into_iter()
+        .map(|s| s.crate_name.unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn orders_dependents_after_their_dependencies() {
+      let scripts = vec![script("b", &["a"]), script("a", &[]), script("c", &["b"])];
+      assert_eq!(ordered_names(scripts), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn breaks_ties_by_crate_name() {
+      let scripts = vec![script("zeta", &[]), script("alpha", &[]), script("mu", &[])];
+      assert_eq!(ordered_names(scripts), vec!["alpha", "mu", "zeta"]);
+    }
+
+    #[test]
+    fn detects_cycles() {
+      let scripts = vec![script("a", &["b"]), script("b", &["a"])];
+      let err = order_global_api_scripts(scripts).unwrap_err();
+      assert!(matches!(err, ScriptOrderingError::Cycle(_)));
+    }
+
+    #[test]
+    fn detects_self_reference_as_cycle() {
+      let scripts = vec![script("a", &["a"])];
+      let err = order_global_api_scripts(scripts).unwrap_err();
+      assert!(matches!(err, ScriptOrderingError::Cycle(_)));
+    }
+
+    fn unique_temp_dir(label: &str) -> PathBuf {
+      let dir = std::env::temp_dir().join(format!(
+        "tauri-plugin-manifest-test-{label}-{:?}",
+        std::thread::current().id()
+      ));
+      let _ = fs::remove_dir_all(&dir);
+      fs::create_dir_all(&dir).expect("failed to create test temp dir");
+      dir
+    }
+
+    #[test]
+    fn manifest_rejects_missing_script() {
+      let dir = unique_temp_dir("missing-script");
+      let manifest_path = dir.join(PLUGIN_MANIFEST_FILE_NAME);
+      fs::write(&manifest_path, "[[global-api-scripts]]\npath = \"does-not-exist.js\"\n").unwrap();
+
+      let err = parse_global_api_scripts_manifest(&manifest_path).unwrap_err();
+      assert!(matches!(err, ManifestError::MissingScript(_)));
+
+      fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn manifest_rejects_invalid_inject_value() {
+      let dir = unique_temp_dir("invalid-inject");
+      fs::write(dir.join("api.js"), "").unwrap();
+      let manifest_path = dir.join(PLUGIN_MANIFEST_FILE_NAME);
+      fs::write(
+        &manifest_path,
+        "[[global-api-scripts]]\npath = \"api.js\"\ninject = \"sometime\"\n",
+      )
+      .unwrap();
+
+      let err = parse_global_api_scripts_manifest(&manifest_path).unwrap_err();
+      assert!(matches!(err, ManifestError::InvalidKey { .. }));
+
+      fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn manifest_rejects_duplicate_script() {
+      let dir = unique_temp_dir("duplicate-script");
+      fs::write(dir.join("api.js"), "").unwrap();
+      let manifest_path = dir.join(PLUGIN_MANIFEST_FILE_NAME);
+      fs::write(
+        &manifest_path,
+        "[[global-api-scripts]]\npath = \"api.js\"\n[[global-api-scripts]]\npath = \"api.js\"\n",
+      )
+      .unwrap();
+
+      let err = parse_global_api_scripts_manifest(&manifest_path).unwrap_err();
+      assert!(matches!(err, ManifestError::DuplicateRegistration(_)));
+
+      fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn manifest_parses_valid_scripts_with_defaults() {
+      let dir = unique_temp_dir("valid-manifest");
+      fs::write(dir.join("api.js"), "").unwrap();
+      let manifest_path = dir.join(PLUGIN_MANIFEST_FILE_NAME);
+      fs::write(&manifest_path, "[[global-api-scripts]]\npath = \"api.js\"\n").unwrap();
+
+      let scripts = parse_global_api_scripts_manifest(&manifest_path).unwrap();
+      assert_eq!(scripts.len(), 1);
+      assert_eq!(scripts[0].inject, GlobalApiScriptInjection::BeforeLoad);
+      assert_eq!(scripts[0].target, None);
+      assert!(scripts[0].after.is_empty());
+
+      fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn target_matches_checks_cfg_target_os_and_arch() {
+      let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+      let saved_os = var("CARGO_CFG_TARGET_OS").ok();
+      let saved_arch = var("CARGO_CFG_TARGET_ARCH").ok();
+
+      unsafe {
+        std::env::set_var("CARGO_CFG_TARGET_OS", "windows");
+        std::env::set_var("CARGO_CFG_TARGET_ARCH", "x86_64");
+      }
+
+      assert!(global_api_script_target_matches(&None));
+      assert!(global_api_script_target_matches(&Some("windows".into())));
+      assert!(global_api_script_target_matches(&Some("x86_64".into())));
+      assert!(!global_api_script_target_matches(&Some("linux".into())));
+
+      match saved_os {
+        Some(v) => unsafe { std::env::set_var("CARGO_CFG_TARGET_OS", v) },
+        None => unsafe { std::env::remove_var("CARGO_CFG_TARGET_OS") },
+      }
+      match saved_arch {
+        Some(v) => unsafe { std::env::set_var("CARGO_CFG_TARGET_ARCH", v) },
+        None => unsafe { std::env::remove_var("CARGO_CFG_TARGET_ARCH") },
+      }
+    }
+
+    #[test]
+    fn save_global_api_scripts_paths_with_manifest_merges_env_and_manifest_scripts() {
+      let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+      let dir = unique_temp_dir("save-env-and-manifest");
+      let env_script_path = dir.join("env-script.js");
+      let manifest_script_path = dir.join("manifest-script.js");
+      fs::write(&env_script_path, "").unwrap();
+      fs::write(&manifest_script_path, "").unwrap();
+
+      let manifest_path = dir.join(PLUGIN_MANIFEST_FILE_NAME);
+      fs::write(&manifest_path, "[[global-api-scripts]]\npath = \"manifest-script.js\"\n").unwrap();
+
+      const PATH_KEY: &str = "DEP_MOCK_PLUGIN_FROM_ENV_GLOBAL_API_SCRIPT_PATH";
+      const NAME_KEY: &str = "DEP_MOCK_PLUGIN_FROM_ENV_GLOBAL_API_SCRIPT_NAME";
+      const VERSION_KEY: &str = "DEP_MOCK_PLUGIN_FROM_ENV_GLOBAL_API_SCRIPT_VERSION";
+
+      unsafe {
+        std::env::set_var(PATH_KEY, &env_script_path);
+        std::env::set_var(NAME_KEY, "mock-plugin-from-env");
+        std::env::set_var(VERSION_KEY, "1.2.3");
+      }
+
+      save_global_api_scripts_paths_with_manifest(&dir, None, Some(&manifest_path))
+        .expect("should save successfully");
+
+      let scripts: Vec<PathBuf> =
+        serde_json::from_str(&fs::read_to_string(dir.join(GLOBAL_API_SCRIPT_FILE_LIST_PATH)).unwrap())
+          .unwrap();
+      assert!(scripts.contains(&env_script_path));
+      assert!(scripts.contains(&manifest_script_path));
+
+      let metadata: Vec<GlobalApiScriptMetadata> = serde_json::from_str(
+        &fs::read_to_string(dir.join(GLOBAL_API_SCRIPT_METADATA_FILE_LIST_PATH)).unwrap(),
+      )
+      .unwrap();
+
+      let env_entry = metadata.iter().find(|m| m.path == env_script_path).unwrap();
+      assert_eq!(env_entry.crate_name.as_deref(), Some("mock-plugin-from-env"));
+      assert_eq!(env_entry.crate_version.as_deref(), Some("1.2.3"));
+      assert_eq!(env_entry.env_key, PATH_KEY);
+
+      let manifest_entry = metadata
+        .iter()
+        .find(|m| m.path == manifest_script_path)
+        .unwrap();
+      assert_eq!(
+        manifest_entry.crate_name.as_deref(),
+        var("CARGO_PKG_NAME").ok().as_deref()
+      );
+
+      unsafe {
+        std::env::remove_var(PATH_KEY);
+        std::env::remove_var(NAME_KEY);
+        std::env::remove_var(VERSION_KEY);
+      }
+      fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn save_global_api_scripts_paths_with_manifest_orders_env_after_manifest_dependency() {
+      let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+      let dir = unique_temp_dir("save-after-order");
+      let manifest_script_path = dir.join("plugin-a.js");
+      let env_script_path = dir.join("plugin-b.js");
+      fs::write(&manifest_script_path, "").unwrap();
+      fs::write(&env_script_path, "").unwrap();
+
+      let manifest_path = dir.join(PLUGIN_MANIFEST_FILE_NAME);
+      fs::write(
+        &manifest_path,
+        "[[global-api-scripts]]\npath = \"plugin-a.js\"\nname = \"plugin-a\"\n",
+      )
+      .unwrap();
+
+      const PATH_KEY: &str = "DEP_MOCK_PLUGIN_B_GLOBAL_API_SCRIPT_PATH";
+      const NAME_KEY: &str = "DEP_MOCK_PLUGIN_B_GLOBAL_API_SCRIPT_NAME";
+      const VERSION_KEY: &str = "DEP_MOCK_PLUGIN_B_GLOBAL_API_SCRIPT_VERSION";
+      const AFTER_KEY: &str = "DEP_MOCK_PLUGIN_B_GLOBAL_API_SCRIPT_AFTER";
+
+      unsafe {
+        std::env::set_var(PATH_KEY, &env_script_path);
+        std::env::set_var(NAME_KEY, "mock-plugin-b");
+        std::env::set_var(VERSION_KEY, "0.1.0");
+        std::env::set_var(AFTER_KEY, "plugin-a");
+      }
+
+      save_global_api_scripts_paths_with_manifest(&dir, None, Some(&manifest_path))
+        .expect("should save successfully");
+
+      let scripts: Vec<PathBuf> =
+        serde_json::from_str(&fs::read_to_string(dir.join(GLOBAL_API_SCRIPT_FILE_LIST_PATH)).unwrap())
+          .unwrap();
+      let manifest_index = scripts.iter().position(|p| p == &manifest_script_path).unwrap();
+      let env_index = scripts.iter().position(|p| p == &env_script_path).unwrap();
+      assert!(
+        manifest_index < env_index,
+        "plugin-b declared `after = [\"plugin-a\"]` so plugin-a's manifest script must come first"
+      );
+
+      let metadata: Vec<GlobalApiScriptMetadata> = serde_json::from_str(
+        &fs::read_to_string(dir.join(GLOBAL_API_SCRIPT_METADATA_FILE_LIST_PATH)).unwrap(),
+      )
+      .unwrap();
+      let env_entry = metadata.iter().find(|m| m.path == env_script_path).unwrap();
+      assert_eq!(env_entry.after, vec!["plugin-a".to_string()]);
+      let manifest_entry = metadata
+        .iter()
+        .find(|m| m.path == manifest_script_path)
+        .unwrap();
+      assert_eq!(manifest_entry.script_name.as_deref(), Some("plugin-a"));
+
+      unsafe {
+        std::env::remove_var(PATH_KEY);
+        std::env::remove_var(NAME_KEY);
+        std::env::remove_var(VERSION_KEY);
+        std::env::remove_var(AFTER_KEY);
+      }
+      fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn script_root_prefers_explicit_override_then_bazel_then_cargo_manifest_dir() {
+      let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+      let saved = [SCRIPT_ROOT_ENV, "BAZEL_OUTPUT_BASE", "CARGO_MANIFEST_DIR"]
+        .map(|key| (key, var(key).ok()));
+
+      // SCRIPT_ROOT_ENV wins over everything else.
+      unsafe {
+        std::env::set_var(SCRIPT_ROOT_ENV, "/explicit/root");
+        std::env::set_var("BAZEL_OUTPUT_BASE", "/bazel/root");
+        std::env::set_var("CARGO_MANIFEST_DIR", "/cargo/root");
+      }
+      assert_eq!(resolve_script_root(), Some(PathBuf::from("/explicit/root")));
+
+      // With no override, BAZEL_OUTPUT_BASE wins over CARGO_MANIFEST_DIR.
+      unsafe {
+        std::env::remove_var(SCRIPT_ROOT_ENV);
+      }
+      assert_eq!(resolve_script_root(), Some(PathBuf::from("/bazel/root")));
+
+      // With neither override nor Bazel, CARGO_MANIFEST_DIR is the default.
+      unsafe {
+        std::env::remove_var("BAZEL_OUTPUT_BASE");
+      }
+      assert_eq!(resolve_script_root(), Some(PathBuf::from("/cargo/root")));
+
+      for (key, value) in saved {
+        match value {
+          Some(value) => unsafe { std::env::set_var(key, value) },
+          None => unsafe { std::env::remove_var(key) },
+        }
+      }
+    }
+  }
 }